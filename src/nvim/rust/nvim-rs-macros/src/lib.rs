@@ -0,0 +1,129 @@
+//! Procedural macros for `nvim-rs`.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, token::Comma, FnArg, GenericArgument, Ident,
+    ItemFn, Pat, PathArguments, ReturnType, Type,
+};
+
+/// Wraps a `fn(...) -> Result<T, NvimError>` into an `extern "C"` shim that nvim's C API can
+/// call directly.
+///
+/// The generated shim takes the same arguments as the wrapped function plus a trailing
+/// `*mut nvim_sys::Error` out-parameter. It:
+///
+/// - catches any panic via `catch_unwind`, converting it into `kErrorTypeException` via
+///   [`NvimError::from_panic_payload`] instead of letting it unwind across the FFI boundary
+///   (which is undefined behavior);
+/// - on `Ok(value)`, returns `value` and leaves the out-parameter untouched (`kErrorTypeNone`);
+/// - on `Err(err)`, fills the out-parameter with `err`'s FFI representation and returns a
+///   zeroed `T`, matching nvim's own convention of ignoring the return value once `Error` is
+///   set.
+///
+/// This replaces the hand-written `as_borrowed_ffi_mut` boilerplate every nvim-exposed function
+/// used to repeat, and guarantees no Rust panic ever crosses into nvim.
+#[proc_macro_attribute]
+pub fn nvim_api(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let func = parse_macro_input!(item as ItemFn);
+
+    let ok_ty = match extract_result_ok_type(&func.sig.output) {
+        Ok(ty) => ty,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let arg_idents: Vec<_> = match func
+        .sig
+        .inputs
+        .iter()
+        .map(|arg| match arg {
+            FnArg::Typed(pat_type) => match &*pat_type.pat {
+                Pat::Ident(pat_ident) => Ok(pat_ident.ident.clone()),
+                pat => Err(syn::Error::new_spanned(
+                    pat,
+                    "#[nvim_api] only supports simple identifier arguments",
+                )),
+            },
+            FnArg::Receiver(recv) => Err(syn::Error::new_spanned(
+                recv,
+                "#[nvim_api] does not support methods",
+            )),
+        })
+        .collect::<syn::Result<_>>()
+    {
+        Ok(idents) => idents,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let vis = &func.vis;
+    let fn_name = &func.sig.ident;
+    let inputs = func.sig.inputs.iter();
+    let inner_name = Ident::new(&format!("__{fn_name}_impl"), Span::call_site());
+
+    let mut inner_func = func.clone();
+    inner_func.sig.ident = inner_name.clone();
+    inner_func.vis = syn::Visibility::Inherited;
+
+    let expanded = quote! {
+        #inner_func
+
+        #[no_mangle]
+        #vis extern "C" fn #fn_name(#(#inputs,)* nvim_api_err: *mut nvim_sys::Error) -> #ok_ty {
+            let result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+                #inner_name(#(#arg_idents),*)
+            }))
+            .unwrap_or_else(|payload| {
+                Err(::nvim_rs::types::result::NvimError::from_panic_payload(payload.as_ref()))
+            });
+
+            match result {
+                Ok(value) => value,
+                Err(err) => {
+                    let ffi_err = ::nvim_rs::types::result::NvimResult::from_result(Err(err)).into_ffi();
+                    unsafe { *nvim_api_err = ffi_err };
+                    unsafe { ::std::mem::zeroed() }
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Extracts `T` out of a `-> Result<T, NvimError>` return type.
+fn extract_result_ok_type(output: &ReturnType) -> syn::Result<Type> {
+    let ReturnType::Type(_, ty) = output else {
+        return Err(syn::Error::new_spanned(
+            output,
+            "#[nvim_api] requires a `Result<T, NvimError>` return type",
+        ));
+    };
+    let Type::Path(type_path) = ty.as_ref() else {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "#[nvim_api] requires a `Result<T, NvimError>` return type",
+        ));
+    };
+    let segment = type_path.path.segments.last().filter(|seg| seg.ident == "Result");
+    let Some(segment) = segment else {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "#[nvim_api] requires a `Result<T, NvimError>` return type",
+        ));
+    };
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return Err(syn::Error::new_spanned(
+            segment,
+            "#[nvim_api] requires a `Result<T, NvimError>` return type",
+        ));
+    };
+    let generic_args: Punctuated<_, Comma> = args.args.clone();
+    match generic_args.first() {
+        Some(GenericArgument::Type(ty)) => Ok(ty.clone()),
+        _ => Err(syn::Error::new_spanned(
+            segment,
+            "#[nvim_api] requires a `Result<T, NvimError>` return type",
+        )),
+    }
+}
@@ -0,0 +1,362 @@
+use std::{
+    collections::HashMap,
+    ffi::CString,
+    mem::{self, ManuallyDrop},
+};
+
+use crate::types::result::{NvimError, NvimErrorKind};
+
+/// Wraps nvim's `Object` (see nvim/api/private/defs.h), a tagged union that can hold any value
+/// nvim's API sends or accepts.
+///
+/// Like [`NvimResult`](crate::types::result::NvimResult), ownership of the underlying FFI value
+/// is tracked through a [`ManuallyDrop`]: `into_ffi` hands it to the C side, and `Drop` frees it
+/// through nvim's allocator if it never got handed off.
+pub struct NvimObject(ManuallyDrop<nvim_sys::Object>);
+
+impl NvimObject {
+    /// Returns nvim's `nil` object.
+    pub fn nil() -> Self {
+        Self(ManuallyDrop::new(nvim_sys::Object {
+            type_: nvim_sys::ObjectType_kObjectTypeNil,
+            data: nvim_sys::ObjectData { boolean: false },
+        }))
+    }
+
+    /// Consumes this object and returns nvim's `Object`.
+    ///
+    /// The caller is responsible for freeing the returned `Object`.
+    pub fn into_ffi(mut self) -> nvim_sys::Object {
+        let inner = unsafe { ManuallyDrop::take(&mut self.0) };
+        mem::forget(self);
+        inner
+    }
+
+    /// Wraps an owned nvim `Object`, taking responsibility for freeing it.
+    ///
+    /// # Safety
+    ///
+    /// `obj` must be a validly-initialized `Object` that nothing else will free.
+    pub unsafe fn from_ffi(obj: nvim_sys::Object) -> Self {
+        Self(ManuallyDrop::new(obj))
+    }
+
+    /// Returns a borrowed Neovim `Object`.
+    ///
+    /// The caller must make sure that the object remains valid when the borrow ends.
+    pub fn as_borrowed_ffi(&self) -> &nvim_sys::Object {
+        &self.0
+    }
+}
+
+impl Drop for NvimObject {
+    fn drop(&mut self) {
+        unsafe { nvim_sys::api_free_object(&mut self.0) }
+    }
+}
+
+impl Default for NvimObject {
+    /// Returns nvim's `nil` object.
+    fn default() -> Self {
+        Self::nil()
+    }
+}
+
+impl From<bool> for NvimObject {
+    fn from(value: bool) -> Self {
+        Self(ManuallyDrop::new(nvim_sys::Object {
+            type_: nvim_sys::ObjectType_kObjectTypeBoolean,
+            data: nvim_sys::ObjectData { boolean: value },
+        }))
+    }
+}
+
+impl From<i64> for NvimObject {
+    fn from(value: i64) -> Self {
+        Self(ManuallyDrop::new(nvim_sys::Object {
+            type_: nvim_sys::ObjectType_kObjectTypeInteger,
+            data: nvim_sys::ObjectData { integer: value },
+        }))
+    }
+}
+
+impl From<f64> for NvimObject {
+    fn from(value: f64) -> Self {
+        Self(ManuallyDrop::new(nvim_sys::Object {
+            type_: nvim_sys::ObjectType_kObjectTypeFloat,
+            data: nvim_sys::ObjectData { floating: value },
+        }))
+    }
+}
+
+impl From<String> for NvimObject {
+    fn from(value: String) -> Self {
+        let cstr = CString::new(value).unwrap_or_else(|_| {
+            CString::new("<string contained a NUL byte>").expect("literal has no NUL byte")
+        });
+        Self(ManuallyDrop::new(nvim_sys::Object {
+            type_: nvim_sys::ObjectType_kObjectTypeString,
+            data: nvim_sys::ObjectData {
+                string: nvim_sys::string_from_cstring(cstr),
+            },
+        }))
+    }
+}
+
+impl From<&str> for NvimObject {
+    fn from(value: &str) -> Self {
+        value.to_owned().into()
+    }
+}
+
+impl From<Vec<NvimObject>> for NvimObject {
+    fn from(value: Vec<NvimObject>) -> Self {
+        let array = nvim_sys::array_from_objects(value.into_iter().map(NvimObject::into_ffi));
+        Self(ManuallyDrop::new(nvim_sys::Object {
+            type_: nvim_sys::ObjectType_kObjectTypeArray,
+            data: nvim_sys::ObjectData { array },
+        }))
+    }
+}
+
+impl From<NvimDictionary> for NvimObject {
+    fn from(value: NvimDictionary) -> Self {
+        Self(ManuallyDrop::new(nvim_sys::Object {
+            type_: nvim_sys::ObjectType_kObjectTypeDictionary,
+            data: nvim_sys::ObjectData {
+                dictionary: value.into_ffi(),
+            },
+        }))
+    }
+}
+
+// Each `TryFrom` below peeks `type_` through `as_borrowed_ffi` *before* deciding whether to take
+// ownership. On a mismatch, `value` is simply dropped at the end of the function, which frees
+// any heap-backed payload (String/Array/Dictionary) through `NvimObject`'s own `Drop` instead of
+// leaking it. Only the matching arm calls `into_ffi`, since that's the one arm that consumes the
+// payload itself.
+
+impl TryFrom<NvimObject> for bool {
+    type Error = NvimError;
+
+    fn try_from(value: NvimObject) -> Result<Self, Self::Error> {
+        match value.as_borrowed_ffi().type_ {
+            nvim_sys::ObjectType_kObjectTypeBoolean => {
+                Ok(unsafe { value.into_ffi().data.boolean })
+            }
+            type_ => Err(type_mismatch_error("Boolean", type_)),
+        }
+    }
+}
+
+impl TryFrom<NvimObject> for i64 {
+    type Error = NvimError;
+
+    fn try_from(value: NvimObject) -> Result<Self, Self::Error> {
+        match value.as_borrowed_ffi().type_ {
+            nvim_sys::ObjectType_kObjectTypeInteger => {
+                Ok(unsafe { value.into_ffi().data.integer })
+            }
+            type_ => Err(type_mismatch_error("Integer", type_)),
+        }
+    }
+}
+
+impl TryFrom<NvimObject> for f64 {
+    type Error = NvimError;
+
+    fn try_from(value: NvimObject) -> Result<Self, Self::Error> {
+        match value.as_borrowed_ffi().type_ {
+            nvim_sys::ObjectType_kObjectTypeFloat => {
+                Ok(unsafe { value.into_ffi().data.floating })
+            }
+            type_ => Err(type_mismatch_error("Float", type_)),
+        }
+    }
+}
+
+impl TryFrom<NvimObject> for String {
+    type Error = NvimError;
+
+    fn try_from(value: NvimObject) -> Result<Self, Self::Error> {
+        match value.as_borrowed_ffi().type_ {
+            nvim_sys::ObjectType_kObjectTypeString => {
+                Ok(unsafe { nvim_sys::string_to_string_lossy(value.into_ffi().data.string) })
+            }
+            type_ => Err(type_mismatch_error("String", type_)),
+        }
+    }
+}
+
+impl TryFrom<NvimObject> for Vec<NvimObject> {
+    type Error = NvimError;
+
+    fn try_from(value: NvimObject) -> Result<Self, Self::Error> {
+        match value.as_borrowed_ffi().type_ {
+            nvim_sys::ObjectType_kObjectTypeArray => {
+                let array = unsafe { value.into_ffi().data.array };
+                Ok(array
+                    .into_iter()
+                    .map(|item| unsafe { NvimObject::from_ffi(item) })
+                    .collect())
+            }
+            type_ => Err(type_mismatch_error("Array", type_)),
+        }
+    }
+}
+
+impl TryFrom<NvimObject> for NvimDictionary {
+    type Error = NvimError;
+
+    fn try_from(value: NvimObject) -> Result<Self, Self::Error> {
+        match value.as_borrowed_ffi().type_ {
+            nvim_sys::ObjectType_kObjectTypeDictionary => {
+                Ok(unsafe { NvimDictionary::from_ffi(value.into_ffi().data.dictionary) })
+            }
+            type_ => Err(type_mismatch_error("Dictionary", type_)),
+        }
+    }
+}
+
+fn type_mismatch_error(expected: &str, actual: nvim_sys::ObjectType) -> NvimError {
+    NvimError::validation(format!(
+        "expected a {expected} object, got {actual:?}"
+    ))
+}
+
+/// Wraps nvim's `Dictionary` (see nvim/api/private/defs.h), an ordered list of string-keyed
+/// [`NvimObject`]s.
+///
+/// Resembles the ergonomic `Map<&str, T>` abstraction libnv provides over name/value pairs,
+/// while keeping the same [`ManuallyDrop`]-based ownership transfer as [`NvimObject`].
+pub struct NvimDictionary(ManuallyDrop<nvim_sys::Dictionary>);
+
+impl NvimDictionary {
+    /// Returns an empty dictionary.
+    pub fn new() -> Self {
+        Self(ManuallyDrop::new(nvim_sys::dictionary_new()))
+    }
+
+    /// Consumes this dictionary and returns nvim's `Dictionary`.
+    ///
+    /// The caller is responsible for freeing the returned `Dictionary`.
+    pub fn into_ffi(mut self) -> nvim_sys::Dictionary {
+        let inner = unsafe { ManuallyDrop::take(&mut self.0) };
+        mem::forget(self);
+        inner
+    }
+
+    /// Wraps an owned nvim `Dictionary`, taking responsibility for freeing it.
+    ///
+    /// # Safety
+    ///
+    /// `dict` must be a validly-initialized `Dictionary` that nothing else will free.
+    pub unsafe fn from_ffi(dict: nvim_sys::Dictionary) -> Self {
+        Self(ManuallyDrop::new(dict))
+    }
+
+    /// Inserts `key`/`value`, replacing any existing entry for `key`.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<NvimObject>) {
+        unsafe { nvim_sys::dictionary_set(&mut self.0, key.into(), value.into().into_ffi()) }
+    }
+}
+
+impl Default for NvimDictionary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for NvimDictionary {
+    fn drop(&mut self) {
+        unsafe { nvim_sys::api_free_dictionary(&mut self.0) }
+    }
+}
+
+impl FromIterator<(String, NvimObject)> for NvimDictionary {
+    fn from_iter<I: IntoIterator<Item = (String, NvimObject)>>(iter: I) -> Self {
+        let mut dict = Self::new();
+        for (key, value) in iter {
+            dict.insert(key, value);
+        }
+        dict
+    }
+}
+
+impl TryFrom<NvimDictionary> for HashMap<String, NvimObject> {
+    type Error = NvimError;
+
+    fn try_from(value: NvimDictionary) -> Result<Self, Self::Error> {
+        let dict = value.into_ffi();
+        unsafe { dict.into_iter() }
+            .map(|(key, value)| {
+                let key = unsafe { nvim_sys::string_to_string_lossy(key) };
+                Ok((key, unsafe { NvimObject::from_ffi(value) }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bool_round_trips() {
+        let obj: NvimObject = true.into();
+        assert_eq!(bool::try_from(obj).unwrap(), true);
+    }
+
+    #[test]
+    fn integer_round_trips() {
+        let obj: NvimObject = 42i64.into();
+        assert_eq!(i64::try_from(obj).unwrap(), 42);
+    }
+
+    #[test]
+    fn float_round_trips() {
+        let obj: NvimObject = 4.2f64.into();
+        assert_eq!(f64::try_from(obj).unwrap(), 4.2);
+    }
+
+    #[test]
+    fn string_round_trips() {
+        let obj: NvimObject = "hello".into();
+        assert_eq!(String::try_from(obj).unwrap(), "hello");
+    }
+
+    #[test]
+    fn array_round_trips() {
+        let obj: NvimObject = vec![NvimObject::from(1i64), NvimObject::from(2i64)].into();
+        let items = Vec::<NvimObject>::try_from(obj).unwrap();
+        let items: Vec<i64> = items.into_iter().map(|item| item.try_into().unwrap()).collect();
+        assert_eq!(items, vec![1, 2]);
+    }
+
+    #[test]
+    fn dictionary_round_trips() {
+        let mut dict = NvimDictionary::new();
+        dict.insert("answer", 42i64);
+        let obj: NvimObject = dict.into();
+        let dict = NvimDictionary::try_from(obj).unwrap();
+        let map: HashMap<String, NvimObject> = dict.try_into().unwrap();
+        let value = map.into_iter().next().unwrap();
+        assert_eq!(value.0, "answer");
+        assert_eq!(i64::try_from(value.1).unwrap(), 42);
+    }
+
+    #[test]
+    fn type_mismatch_returns_a_validation_error_without_leaking() {
+        // Dictionary and Array are the heap-backed variants; converting them to a mismatched
+        // type must still free the payload (via `NvimObject`'s `Drop`) instead of leaking it.
+        let mut dict = NvimDictionary::new();
+        dict.insert("a", "b");
+        let obj: NvimObject = dict.into();
+        let err = bool::try_from(obj).unwrap_err();
+        assert!(matches!(err.kind, NvimErrorKind::Validation));
+
+        let obj: NvimObject = vec![NvimObject::from("leak me")].into();
+        let err = bool::try_from(obj).unwrap_err();
+        assert!(matches!(err.kind, NvimErrorKind::Validation));
+    }
+}
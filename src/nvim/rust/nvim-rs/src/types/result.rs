@@ -40,8 +40,12 @@ impl NvimResult {
                 let type_ = match err.kind {
                     NvimErrorKind::Exception => nvim_sys::ErrorType_kErrorTypeException,
                     NvimErrorKind::Validation => nvim_sys::ErrorType_kErrorTypeValidation,
+                    NvimErrorKind::Unknown(type_) => type_,
                 };
-                let msg = err.msg.as_ptr() as *mut i8;
+                // `into_raw` transfers ownership of the buffer to the returned `Error` instead
+                // of just borrowing a pointer into `err`, which would otherwise dangle once
+                // `err` is dropped at the end of this arm.
+                let msg = err.msg.into_raw();
                 (type_, msg)
             }
         };
@@ -53,20 +57,18 @@ impl NvimResult {
         let nvim_sys::Error { type_, msg } = self.into_ffi();
         match type_ {
             nvim_sys::ErrorType_kErrorTypeNone => Ok(()),
-            nvim_sys::ErrorType_kErrorTypeException => Err(NvimError {
-                kind: NvimErrorKind::Exception,
-                msg: unsafe { cstring_from_raw_check_null(msg) },
-            }),
-            nvim_sys::ErrorType_kErrorTypeValidation => Err(NvimError {
-                kind: NvimErrorKind::Validation,
-                msg: unsafe { cstring_from_raw_check_null(msg) },
-            }),
-            _ => {
-                panic!(
-                    "Encounter unknown error value ({:?}) when converting nvim error",
-                    type_
-                );
-            }
+            nvim_sys::ErrorType_kErrorTypeException => Err(NvimError::new(
+                NvimErrorKind::Exception,
+                unsafe { cstring_from_raw_or_empty(msg) },
+            )),
+            nvim_sys::ErrorType_kErrorTypeValidation => Err(NvimError::new(
+                NvimErrorKind::Validation,
+                unsafe { cstring_from_raw_or_empty(msg) },
+            )),
+            type_ => Err(NvimError::new(
+                NvimErrorKind::Unknown(type_),
+                unsafe { cstring_from_raw_or_empty(msg) },
+            )),
         }
     }
 }
@@ -95,6 +97,11 @@ impl From<NvimResult> for std::result::Result<(), NvimError> {
 pub enum NvimErrorKind {
     Exception,
     Validation,
+    /// An `ErrorType` we don't recognize yet, carrying the raw `type_` value.
+    ///
+    /// Nvim may introduce new error types over time; falling back to this variant instead of
+    /// panicking keeps the conversion total and forward-compatible.
+    Unknown(u32),
 }
 
 #[derive(Debug)]
@@ -102,23 +109,201 @@ pub enum NvimErrorKind {
 pub struct NvimError {
     pub kind: NvimErrorKind,
     pub msg: CString,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    vim_error_code: Option<VimErrorCode>,
+    #[cfg(feature = "backtrace")]
+    backtrace: std::backtrace::Backtrace,
+}
+
+impl NvimError {
+    fn new(kind: NvimErrorKind, msg: CString) -> Self {
+        let vim_error_code = matches!(kind, NvimErrorKind::Exception)
+            .then(|| parse_vim_error_code(&msg))
+            .flatten();
+        Self {
+            kind,
+            msg,
+            source: None,
+            vim_error_code,
+            #[cfg(feature = "backtrace")]
+            backtrace: std::backtrace::Backtrace::capture(),
+        }
+    }
+
+    /// Attaches `source` as the cause of this error, so it is returned from
+    /// [`Error::source`](std::error::Error::source).
+    ///
+    /// This lets callers wrapping an nvim failure inside a larger operation preserve the causal
+    /// chain through `?`.
+    pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    /// Returns the backtrace captured when this error was created.
+    ///
+    /// Only available when this crate's `backtrace` feature is enabled (declared in this
+    /// crate's `Cargo.toml`, not a dependency's); see
+    /// [`std::backtrace::Backtrace::capture`] for when it is actually populated. Captured in
+    /// `NvimError::new` rather than only in `from_result`'s `Err` arm, so every `NvimError` gets
+    /// one regardless of which constructor built it.
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> &std::backtrace::Backtrace {
+        &self.backtrace
+    }
+
+    /// Returns the Vim error code (e.g. `492` for `E492: Not an editor command`), if this is an
+    /// `Exception` whose `msg` carries one; the first `E<digits>:` token in `msg` is used, even
+    /// when it does not start the message (see `parse_vim_error_code`).
+    ///
+    /// Lets callers `match` on the numeric code instead of doing fragile substring checks on
+    /// [`to_string_lossy`](CString::to_string_lossy).
+    pub fn code(&self) -> Option<u32> {
+        self.vim_error_code.map(|code| code.0)
+    }
+
+    /// Classifies the Vim error code (if any) into a coarse family.
+    pub fn vim_kind(&self) -> Option<VimErrorKind> {
+        self.vim_error_code.map(VimErrorCode::kind)
+    }
+
+    /// Builds an `Exception` error out of a caught panic's payload.
+    ///
+    /// Used by `#[nvim_api]`-generated shims to report a Rust panic as an nvim exception instead
+    /// of letting it unwind across the FFI boundary.
+    pub fn from_panic_payload(payload: &(dyn std::any::Any + Send)) -> Self {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked with a non-string payload".to_string());
+        let msg = CString::new(msg)
+            .unwrap_or_else(|_| CString::new("panic message contained a NUL byte").unwrap());
+        Self::new(NvimErrorKind::Exception, msg)
+    }
+
+    /// Builds a `Validation` error out of `msg`, e.g. a type-mismatch while converting an
+    /// [`Object`](crate::types::object::NvimObject) into a Rust value.
+    pub(crate) fn validation(msg: impl Into<Vec<u8>>) -> Self {
+        let msg = CString::new(msg)
+            .unwrap_or_else(|_| CString::new("error message contained a NUL byte").unwrap());
+        Self::new(NvimErrorKind::Validation, msg)
+    }
+}
+
+/// A parsed Vim `E###` exception code (see `:help error-messages`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct VimErrorCode(u32);
+
+impl VimErrorCode {
+    fn kind(self) -> VimErrorKind {
+        match self.0 {
+            492 => VimErrorKind::CommandNotFound,
+            474 | 475 | 1206 => VimErrorKind::InvalidArgument,
+            _ => VimErrorKind::Exception,
+        }
+    }
+}
+
+/// Coarse family of a [`VimErrorCode`], for callers that want to `match` rather than compare
+/// raw codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VimErrorKind {
+    /// `E492`: the exception's message names a command nvim doesn't recognize.
+    CommandNotFound,
+    /// E.g. `E474`/`E475`/`E1206`: the exception's message reports an invalid argument.
+    InvalidArgument,
+    /// Any other Vim exception family.
+    Exception,
+}
+
+/// Parses an `E<digits>:` token out of a Vim exception message, per `:help error-messages`.
+///
+/// Real nvim exception messages often wrap the code rather than start with it directly, e.g.
+/// `Vim:E492: Not an editor command` or `Vim(command):E475: ...`, so every `E` in `msg` is tried
+/// as a possible start of the token rather than requiring it at byte 0.
+fn parse_vim_error_code(msg: &CString) -> Option<VimErrorCode> {
+    let msg = msg.to_str().ok()?;
+    msg.match_indices('E').find_map(|(i, _)| {
+        let rest = &msg[i + 1..];
+        let (digits, _) = rest.split_once(':')?;
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok().map(VimErrorCode)
+    })
 }
 
 impl Display for NvimError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let kind = match self.kind {
-            NvimErrorKind::Exception => "Exception: ",
-            NvimErrorKind::Validation => "Validation: ",
-        };
-        write!(f, "{kind}: {}", self.msg.to_string_lossy())
+        match self.kind {
+            NvimErrorKind::Exception => write!(f, "Exception: {}", self.msg.to_string_lossy()),
+            NvimErrorKind::Validation => write!(f, "Validation: {}", self.msg.to_string_lossy()),
+            NvimErrorKind::Unknown(type_) => {
+                write!(f, "Unknown({type_}): {}", self.msg.to_string_lossy())
+            }
+        }
     }
 }
 
-impl std::error::Error for NvimError {}
+impl std::error::Error for NvimError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|err| err.as_ref() as &(dyn std::error::Error + 'static))
+    }
+}
 
-unsafe fn cstring_from_raw_check_null(msg: *mut c_char) -> CString {
+/// Converts a raw `msg` pointer into a `CString`, treating null as empty rather than panicking.
+///
+/// `into_result` must stay total: nvim occasionally hands us an `Error` whose `type_` is
+/// recognized but whose `msg` is null, and that's still a recoverable (if uninformative) error,
+/// not a reason to abort inside the FFI boundary.
+unsafe fn cstring_from_raw_or_empty(msg: *mut c_char) -> CString {
     if msg.is_null() {
-        panic!("Try to covert a null pointer to a CString");
+        return CString::default();
     }
     unsafe { CString::from_raw(msg) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code_of(msg: &str) -> Option<u32> {
+        parse_vim_error_code(&CString::new(msg).unwrap()).map(|code| code.0)
+    }
+
+    #[test]
+    fn parses_a_leading_code() {
+        assert_eq!(code_of("E492: Not an editor command"), Some(492));
+    }
+
+    #[test]
+    fn parses_a_wrapped_code() {
+        assert_eq!(code_of("Vim:E492: Not an editor command"), Some(492));
+        assert_eq!(code_of("Vim(command):E475: Invalid argument"), Some(475));
+    }
+
+    #[test]
+    fn returns_none_without_a_colon() {
+        assert_eq!(code_of("E492 Not an editor command"), None);
+    }
+
+    #[test]
+    fn returns_none_with_empty_digits() {
+        assert_eq!(code_of("E: Not an editor command"), None);
+    }
+
+    #[test]
+    fn returns_none_without_any_code() {
+        assert_eq!(code_of("Not an editor command"), None);
+    }
+
+    #[test]
+    fn kind_classifies_known_families() {
+        assert_eq!(code_of("E492: ...").map(VimErrorCode).map(VimErrorCode::kind), Some(VimErrorKind::CommandNotFound));
+        assert_eq!(code_of("E475: ...").map(VimErrorCode).map(VimErrorCode::kind), Some(VimErrorKind::InvalidArgument));
+        assert_eq!(code_of("E999: ...").map(VimErrorCode).map(VimErrorCode::kind), Some(VimErrorKind::Exception));
+    }
+}